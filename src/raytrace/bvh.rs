@@ -0,0 +1,364 @@
+use crate::raytrace::Ray;
+use crate::types::Float;
+use crate::vector::Vector3D;
+
+/// A leaf payload tagged with its axis-aligned bounding box. `Id` identifies
+/// the primitive the box encloses (for meshes, the triangle index).
+#[derive(Debug, Clone, Copy)]
+pub struct GenericBound<Id: Copy, F: Float> {
+    id: Id,
+
+    min_pt: Vector3D<F>,
+    max_pt: Vector3D<F>,
+}
+
+impl<Id: Copy, F: Float> GenericBound<Id, F> {
+    pub fn new(id: Id, min_pt: Vector3D<F>, max_pt: Vector3D<F>) -> Self {
+        Self { id, min_pt, max_pt }
+    }
+
+    pub fn get(&self) -> Id {
+        self.id
+    }
+
+    fn min_pt(&self) -> Vector3D<F> {
+        self.min_pt
+    }
+
+    fn max_pt(&self) -> Vector3D<F> {
+        self.max_pt
+    }
+
+    fn centroid(&self) -> Vector3D<F> {
+        (self.min_pt + self.max_pt) * F::from(0.5).unwrap()
+    }
+}
+
+/// An axis-aligned box accumulated during construction.
+#[derive(Debug, Clone, Copy)]
+struct Aabb<F: Float> {
+    min_pt: Vector3D<F>,
+    max_pt: Vector3D<F>,
+}
+
+impl<F: Float> Aabb<F> {
+    fn empty() -> Self {
+        let inf = F::max_value();
+        Self {
+            min_pt: Vector3D::new(inf, inf, inf),
+            max_pt: Vector3D::new(-inf, -inf, -inf),
+        }
+    }
+
+    fn join_point(&mut self, p: Vector3D<F>) {
+        self.min_pt = Vector3D::new(
+            self.min_pt.x.min(p.x),
+            self.min_pt.y.min(p.y),
+            self.min_pt.z.min(p.z),
+        );
+        self.max_pt = Vector3D::new(
+            self.max_pt.x.max(p.x),
+            self.max_pt.y.max(p.y),
+            self.max_pt.z.max(p.z),
+        );
+    }
+
+    fn join_box<Id: Copy>(&mut self, bound: &GenericBound<Id, F>) {
+        self.join_point(bound.min_pt());
+        self.join_point(bound.max_pt());
+    }
+
+    fn join(&mut self, other: &Aabb<F>) {
+        self.join_point(other.min_pt);
+        self.join_point(other.max_pt);
+    }
+
+    /// Surface area; zero for an empty box.
+    fn surface_area(&self) -> F {
+        let d = self.max_pt - self.min_pt;
+        if d.x < F::zero() || d.y < F::zero() || d.z < F::zero() {
+            return F::zero();
+        }
+        let two = F::from(2.0).unwrap();
+        two * (d.x * d.y + d.y * d.z + d.z * d.x)
+    }
+}
+
+#[derive(Clone)]
+enum Node<F: Float> {
+    Leaf {
+        start: usize,
+        len: usize,
+    },
+    Interior {
+        bounds: Aabb<F>,
+        left: usize,
+        right: usize,
+    },
+}
+
+/// Bounding volume hierarchy built with a binned surface-area heuristic.
+#[derive(Clone)]
+pub struct BVH<Id: Copy, F: Float> {
+    bound_vec: Vec<GenericBound<Id, F>>,
+    nodes: Vec<Node<F>>,
+    leaf_bounds: Vec<Aabb<F>>,
+    root: Option<usize>,
+}
+
+const BIN_COUNT: usize = 12;
+const LEAF_THRESHOLD: usize = 2;
+
+impl<Id: Copy, F: Float> BVH<Id, F> {
+    pub fn new(bound_vec: Vec<GenericBound<Id, F>>) -> Self {
+        let mut bvh = Self {
+            bound_vec,
+            nodes: Vec::new(),
+            leaf_bounds: Vec::new(),
+            root: None,
+        };
+
+        if !bvh.bound_vec.is_empty() {
+            let len = bvh.bound_vec.len();
+            let root = bvh.build(0, len);
+            bvh.root = Some(root);
+        }
+
+        bvh
+    }
+
+    pub fn bound_vec(&self) -> &[GenericBound<Id, F>] {
+        &self.bound_vec
+    }
+
+    /// Build the subtree spanning `bound_vec[start..start + len]`, partitioning
+    /// the slice in place, and return the index of the created node.
+    fn build(&mut self, start: usize, len: usize) -> usize {
+        let mut node_bounds = Aabb::empty();
+        for bound in &self.bound_vec[start..start + len] {
+            node_bounds.join_box(bound);
+        }
+
+        if len <= LEAF_THRESHOLD {
+            return self.push_leaf(start, len, node_bounds);
+        }
+
+        // Bin the primitive centroids along each axis and sweep the bin
+        // boundaries, minimizing C = A_L·N_L + A_R·N_R.
+        let mut centroid_bounds = Aabb::empty();
+        for bound in &self.bound_vec[start..start + len] {
+            centroid_bounds.join_point(bound.centroid());
+        }
+
+        let mut best_axis = 0usize;
+        let mut best_split = 0usize;
+        let mut best_cost = F::max_value();
+
+        for axis in 0..3 {
+            let lo = axis_of(centroid_bounds.min_pt, axis);
+            let hi = axis_of(centroid_bounds.max_pt, axis);
+            let span = hi - lo;
+            if span <= F::zero() {
+                continue;
+            }
+
+            let mut counts = [0usize; BIN_COUNT];
+            let mut boxes = [Aabb::empty(); BIN_COUNT];
+            let scale = F::from(BIN_COUNT as f64).unwrap() / span;
+
+            for bound in &self.bound_vec[start..start + len] {
+                let c = axis_of(bound.centroid(), axis);
+                let mut b = ((c - lo) * scale).to_usize().unwrap_or(0);
+                if b >= BIN_COUNT {
+                    b = BIN_COUNT - 1;
+                }
+                counts[b] += 1;
+                boxes[b].join_box(bound);
+            }
+
+            // Sweep boundaries between bin i and i+1.
+            for split in 1..BIN_COUNT {
+                let mut left = Aabb::empty();
+                let mut left_n = 0usize;
+                for i in 0..split {
+                    left.join(&boxes[i]);
+                    left_n += counts[i];
+                }
+                let mut right = Aabb::empty();
+                let mut right_n = 0usize;
+                for i in split..BIN_COUNT {
+                    right.join(&boxes[i]);
+                    right_n += counts[i];
+                }
+                if left_n == 0 || right_n == 0 {
+                    continue;
+                }
+
+                let cost = left.surface_area() * F::from(left_n as f64).unwrap()
+                    + right.surface_area() * F::from(right_n as f64).unwrap();
+                if cost < best_cost {
+                    best_cost = cost;
+                    best_axis = axis;
+                    best_split = split;
+                }
+            }
+        }
+
+        // Fall back to a leaf when no split beats the leaf cost.
+        let leaf_cost = node_bounds.surface_area() * F::from(len as f64).unwrap();
+        if best_cost >= leaf_cost || best_cost == F::max_value() {
+            return self.push_leaf(start, len, node_bounds);
+        }
+
+        // Partition the slice around the chosen bin boundary.
+        let lo = axis_of(centroid_bounds.min_pt, best_axis);
+        let hi = axis_of(centroid_bounds.max_pt, best_axis);
+        let scale = F::from(BIN_COUNT as f64).unwrap() / (hi - lo);
+        let boundary = F::from(best_split as f64).unwrap();
+
+        let slice = &mut self.bound_vec[start..start + len];
+        let mut mid = 0usize;
+        for i in 0..slice.len() {
+            let c = axis_of(slice[i].centroid(), best_axis);
+            let mut b = ((c - lo) * scale).to_usize().unwrap_or(0);
+            if b >= BIN_COUNT {
+                b = BIN_COUNT - 1;
+            }
+            if F::from(b as f64).unwrap() < boundary {
+                slice.swap(i, mid);
+                mid += 1;
+            }
+        }
+
+        // Guard against a partition that leaves one side empty: recursing on
+        // the unchanged range would never terminate. Fall back to an
+        // equal-counts median split along the chosen axis.
+        if mid == 0 || mid == len {
+            slice.sort_by(|a, b| {
+                axis_of(a.centroid(), best_axis)
+                    .partial_cmp(&axis_of(b.centroid(), best_axis))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            mid = len / 2;
+        }
+
+        let left = self.build(start, mid);
+        let right = self.build(start + mid, len - mid);
+
+        self.nodes.push(Node::Interior {
+            bounds: node_bounds,
+            left,
+            right,
+        });
+        self.nodes.len() - 1
+    }
+
+    fn push_leaf(&mut self, start: usize, len: usize, bounds: Aabb<F>) -> usize {
+        self.nodes.push(Node::Leaf { start, len });
+        let idx = self.nodes.len() - 1;
+        // Keep leaf index aligned with `leaf_bounds` for the slab test.
+        while self.leaf_bounds.len() <= idx {
+            self.leaf_bounds.push(Aabb::empty());
+        }
+        self.leaf_bounds[idx] = bounds;
+        idx
+    }
+
+    pub fn hit(&self, ray: &Ray<F>) -> Vec<&GenericBound<Id, F>> {
+        let mut out = Vec::new();
+        if let Some(root) = self.root {
+            self.traverse(root, ray, &mut out);
+        }
+        out
+    }
+
+    fn traverse<'a>(&'a self, node: usize, ray: &Ray<F>, out: &mut Vec<&'a GenericBound<Id, F>>) {
+        match &self.nodes[node] {
+            Node::Leaf { start, len } => {
+                if slab_hit(&self.leaf_bounds[node], ray) {
+                    for bound in &self.bound_vec[*start..*start + *len] {
+                        out.push(bound);
+                    }
+                }
+            }
+            Node::Interior {
+                bounds,
+                left,
+                right,
+            } => {
+                if slab_hit(bounds, ray) {
+                    self.traverse(*left, ray, out);
+                    self.traverse(*right, ray, out);
+                }
+            }
+        }
+    }
+}
+
+fn axis_of<F: Float>(v: Vector3D<F>, axis: usize) -> F {
+    match axis {
+        0 => v.x,
+        1 => v.y,
+        _ => v.z,
+    }
+}
+
+fn slab_hit<F: Float>(bounds: &Aabb<F>, ray: &Ray<F>) -> bool {
+    let origin = ray.origin();
+    let w_i = ray.direction();
+    let inv_dir = Vector3D::new(
+        F::one() / w_i.x,
+        F::one() / w_i.y,
+        F::one() / w_i.z,
+    );
+
+    let (tx_min, tx_max) = if w_i.x >= F::zero() {
+        ((bounds.min_pt.x - origin.x) * inv_dir.x, (bounds.max_pt.x - origin.x) * inv_dir.x)
+    } else {
+        ((bounds.max_pt.x - origin.x) * inv_dir.x, (bounds.min_pt.x - origin.x) * inv_dir.x)
+    };
+    let (ty_min, ty_max) = if w_i.y >= F::zero() {
+        ((bounds.min_pt.y - origin.y) * inv_dir.y, (bounds.max_pt.y - origin.y) * inv_dir.y)
+    } else {
+        ((bounds.max_pt.y - origin.y) * inv_dir.y, (bounds.min_pt.y - origin.y) * inv_dir.y)
+    };
+    let (tz_min, tz_max) = if w_i.z >= F::zero() {
+        ((bounds.min_pt.z - origin.z) * inv_dir.z, (bounds.max_pt.z - origin.z) * inv_dir.z)
+    } else {
+        ((bounds.max_pt.z - origin.z) * inv_dir.z, (bounds.min_pt.z - origin.z) * inv_dir.z)
+    };
+
+    let t_enter = tx_min.max(ty_min.max(tz_min));
+    let t_exit = tx_max.min(ty_max.min(tz_max));
+
+    let epsilon = F::from(1e-4f32).unwrap();
+    t_enter < t_exit + epsilon && t_exit > F::zero()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A unit box centred at `x` on the axis, clustered far from its siblings.
+    fn unit_box(id: usize, x: f64) -> GenericBound<usize, f64> {
+        GenericBound::new(
+            id,
+            Vector3D::new(x - 0.5, -0.5, -0.5),
+            Vector3D::new(x + 0.5, 0.5, 0.5),
+        )
+    }
+
+    #[test]
+    fn sah_split_separates_clustered_primitives() {
+        // Four boxes spread along x; the SAH split must group them so a ray
+        // aimed at one cluster prunes the far ones instead of returning all.
+        let bounds = vec![unit_box(0, 0.0), unit_box(1, 10.0), unit_box(2, 20.0), unit_box(3, 30.0)];
+        let bvh = BVH::new(bounds);
+
+        let ray = Ray::new(Vector3D::new(20.0, -5.0, 0.0), Vector3D::new(0.0, 1.0, 0.0));
+        let ids: Vec<usize> = bvh.hit(&ray).iter().map(|b| b.get()).collect();
+
+        assert!(ids.contains(&2), "ray through x=20 must reach that box");
+        assert!(!ids.contains(&0), "far box at x=0 must be pruned by the split");
+    }
+}
@@ -0,0 +1,154 @@
+use crate::raytrace::{Incident, ProcessedIncident};
+use crate::raytrace::materials::{BRDFReflector, Material};
+use crate::types::Float;
+use crate::vector::Vector3D;
+
+/// Rough conductor/plastic surface using a Cook-Torrance microfacet model with
+/// a GGX/Trowbridge-Reitz normal distribution. As the roughness `alpha`
+/// approaches zero the specular lobe collapses toward a perfect mirror.
+pub struct Glossy<F: Float> {
+    f0: Vector3D<F>,
+    alpha: F,
+}
+
+impl<F: Float> Glossy<F> {
+    pub fn new(f0: Vector3D<F>, alpha: F) -> Self {
+        Self { f0, alpha }
+    }
+
+    /// GGX normal distribution term `D(h)`.
+    fn distribution(&self, n_dot_h: F) -> F {
+        let a2 = self.alpha * self.alpha;
+        let pi = F::from(std::f64::consts::PI).unwrap();
+        let denom = n_dot_h * n_dot_h * (a2 - F::one()) + F::one();
+        a2 / (pi * denom * denom)
+    }
+
+    /// Smith masking-shadowing via the Schlick-GGX approximation, `k = alpha/2`.
+    fn geometry(&self, n_dot_i: F, n_dot_r: F) -> F {
+        let k = self.alpha / F::from(2.0).unwrap();
+        let g1 = |c: F| c / (c * (F::one() - k) + k);
+        g1(n_dot_i) * g1(n_dot_r)
+    }
+
+    /// Schlick Fresnel term, tinted by the surface's `f0` reflectance.
+    fn fresnel(&self, cos_theta: F) -> Vector3D<F> {
+        let m = (F::one() - cos_theta).powi(5);
+        self.f0 + (Vector3D::new(F::one(), F::one(), F::one()) - self.f0) * m
+    }
+}
+
+/// Orthonormal basis `(t, b, n)` with `n` as the third axis.
+fn local_frame<F: Float>(n: Vector3D<F>) -> (Vector3D<F>, Vector3D<F>) {
+    let up = if n.z.abs() < F::from(0.999).unwrap() {
+        Vector3D::new(F::zero(), F::zero(), F::one())
+    } else {
+        Vector3D::new(F::one(), F::zero(), F::zero())
+    };
+    let t = normalize(up.cross(n));
+    let b = n.cross(t);
+    (t, b)
+}
+
+fn normalize<F: Float>(v: Vector3D<F>) -> Vector3D<F> {
+    v * (F::one() / v.dot(v).sqrt())
+}
+
+fn reflect<F: Float>(w_i: Vector3D<F>, h: Vector3D<F>) -> Vector3D<F> {
+    let two = F::from(2.0).unwrap();
+    h * (two * w_i.dot(h)) - w_i
+}
+
+impl<F: Float> BRDFReflector<F> for Glossy<F> {
+    fn f_r(
+        &self,
+        _coords: Vector3D<F>,
+        w_i: Vector3D<F>, w_r: Vector3D<F>,
+        normal: Vector3D<F>,
+        _seed: F,
+    ) -> Vector3D<F> {
+        let h = normalize(w_i + w_r);
+
+        let n_dot_i = normal.dot(w_i);
+        let n_dot_r = normal.dot(w_r);
+        if n_dot_i <= F::zero() || n_dot_r <= F::zero() {
+            return Vector3D::new(F::zero(), F::zero(), F::zero());
+        }
+
+        let d = self.distribution(normal.dot(h));
+        let g = self.geometry(n_dot_i, n_dot_r);
+        let f = self.fresnel(w_r.dot(h));
+
+        let four = F::from(4.0).unwrap();
+        f * (d * g / (four * n_dot_i * n_dot_r))
+    }
+
+    fn sample_reflected(
+        &self,
+        _coords: Vector3D<F>,
+        w_i: Vector3D<F>,
+        normal: Vector3D<F>,
+        seed: F,
+    ) -> (Vector3D<F>, F) {
+        let u1 = seed;
+        let u2 = F::sample_rand();
+
+        // Importance-sample a microfacet normal from the GGX distribution.
+        let pi = F::from(std::f64::consts::PI).unwrap();
+        let theta = (self.alpha * (u1 / (F::one() - u1)).sqrt()).atan();
+        let phi = F::from(2.0).unwrap() * pi * u2;
+
+        let sin_theta = theta.sin();
+        let cos_theta = theta.cos();
+
+        let (t, b) = local_frame(normal);
+        let h = t * (sin_theta * phi.cos())
+            + b * (sin_theta * phi.sin())
+            + normal * cos_theta;
+
+        let w_r = reflect(w_i, h);
+
+        let n_dot_h = normal.dot(h);
+        let w_r_dot_h = w_r.dot(h).abs();
+        let pdf = if w_r_dot_h <= F::zero() {
+            F::zero()
+        } else {
+            self.distribution(n_dot_h) * n_dot_h / (F::from(4.0).unwrap() * w_r_dot_h)
+        };
+
+        (w_r, pdf)
+    }
+
+    fn pdf(
+        &self,
+        _coords: Vector3D<F>,
+        w_i: Vector3D<F>, w_r: Vector3D<F>,
+        normal: Vector3D<F>,
+    ) -> F {
+        let h = normalize(w_i + w_r);
+        let n_dot_h = normal.dot(h);
+        let w_r_dot_h = w_r.dot(h).abs();
+        if w_r_dot_h <= F::zero() {
+            return F::zero();
+        }
+        self.distribution(n_dot_h) * n_dot_h / (F::from(4.0).unwrap() * w_r_dot_h)
+    }
+}
+
+impl<F: Float> Material<F> for Glossy<F> {
+    fn interact(&self, incident: Incident<F>, seed: F) -> ProcessedIncident<F> {
+        let brdf_incident = self.reflect(&incident, seed);
+        ProcessedIncident::from_brdf(incident, brdf_incident)
+    }
+
+    fn interact_predetermined(
+        &self,
+        incident: Incident<F>,
+        w_r: Vector3D<F>,
+        pdf: F,
+        seed: F,
+    ) -> ProcessedIncident<F> {
+        let brdf_incident = self.reflect_predetermined(&incident, w_r, pdf, seed);
+        ProcessedIncident::from_brdf(incident, brdf_incident)
+    }
+}
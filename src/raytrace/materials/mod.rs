@@ -4,10 +4,12 @@ use crate::types::Float;
 use crate::vector::Vector3D;
 
 mod diffuse;
+mod glossy;
 mod refract;
 
 pub use diffuse::Diffuse;
-pub use refract::Refract;
+pub use glossy::Glossy;
+pub use refract::{Dielectric, Refract};
 
 pub trait Material<F: Float> {
     fn interact(
@@ -39,6 +41,20 @@ pub trait BRDFReflector<F: Float> {
         normal: Vector3D<F>,
         seed: F,
     ) -> (Vector3D<F>, F);
+    /// Solid-angle pdf that `sample_reflected` would assign to the outgoing
+    /// direction `w_r`, for weighting BRDF sampling against light sampling
+    /// with multiple importance sampling; no integrator code calls it. The
+    /// default is the cosine-weighted density `max(n·w_r, 0)/π` used by
+    /// Lambertian diffuse; `Glossy` overrides it with its GGX density.
+    fn pdf(
+        &self,
+        _coords: Vector3D<F>,
+        _w_i: Vector3D<F>, w_r: Vector3D<F>,
+        normal: Vector3D<F>,
+    ) -> F {
+        let pi = F::from(std::f64::consts::PI).unwrap();
+        w_r.dot(normal).max(F::zero()) / pi
+    }
     fn reflect_predetermined(
         &self,
         incident: &Incident<F>,
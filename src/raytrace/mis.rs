@@ -0,0 +1,50 @@
+use crate::types::Float;
+use crate::vector::Vector3D;
+
+/// Balance the two direct-lighting strategies with the power heuristic
+/// `w = p_a² / (p_a² + p_b²)`, where `p_a` and `p_b` are the solid-angle pdfs
+/// the two strategies assign to the realized direction. Returns zero when both
+/// pdfs vanish so the combined estimator stays well defined.
+pub fn power_heuristic<F: Float>(p_a: F, p_b: F) -> F {
+    let a2 = p_a * p_a;
+    let b2 = p_b * p_b;
+    let denom = a2 + b2;
+    if denom <= F::zero() {
+        F::zero()
+    } else {
+        a2 / denom
+    }
+}
+
+/// An emitter that can report the solid-angle pdf of a shadow ray targeting it,
+/// so next-event estimation can MIS-weight the light-sampling strategy against
+/// BRDF sampling. Implemented by `Mesh` and `Sphere`; no integrator code
+/// gathers emitters into a list or calls this trait.
+pub trait Emitter<F: Float> {
+    /// Solid-angle pdf of sampling the direction `w_r` from the shading point
+    /// `coords` toward this emitter. `distance` is the hit distance along the
+    /// ray and `light_normal` the surface normal at the sampled point; the area
+    /// pdf `1/area` is converted to solid angle by `dist² / (area · |cosθ|)`.
+    fn directional_pdf(
+        &self,
+        coords: Vector3D<F>,
+        w_r: Vector3D<F>,
+        distance: F,
+        light_normal: Vector3D<F>,
+    ) -> F;
+}
+
+/// Convert an area-measure pdf `area_pdf` (= `1/area`) to a solid-angle pdf for
+/// a ray of length `distance` striking a surface whose normal is `light_normal`.
+pub fn area_to_solid_angle<F: Float>(
+    area_pdf: F,
+    w_r: Vector3D<F>,
+    distance: F,
+    light_normal: Vector3D<F>,
+) -> F {
+    let cos = w_r.dot(light_normal).abs();
+    if cos <= F::zero() {
+        return F::zero();
+    }
+    area_pdf * distance * distance / cos
+}
@@ -0,0 +1,441 @@
+use crate::raytrace::{Incident, ProcessedIncident, Ray};
+use crate::raytrace::objects::{Bounded, LightInteractable, LightSample, PartialBounded, RayTraceable};
+use crate::raytrace::materials::Material;
+
+use crate::types::Float;
+use crate::vector::Vector3D;
+
+/// A signed distance function: `distance(p)` is negative inside the surface,
+/// positive outside, and zero on it. Implementors describe geometry
+/// analytically so it can be sphere-traced without triangulation.
+pub trait SignedDistance<F: Float> {
+    fn distance(&self, p: Vector3D<F>) -> F;
+}
+
+/// An implicit object: an SDF paired with a material. Ray intersection is
+/// performed by sphere tracing and the shading delegates to the existing
+/// `Material` trait, so implicit surfaces drop into scenes next to `Mesh`.
+pub struct Implicit<F: Float> {
+    name: String,
+
+    sdf: Box<dyn SignedDistance<F>>,
+
+    min_pt: Vector3D<F>,
+    max_pt: Vector3D<F>,
+
+    material: Box<dyn Material<F>>,
+}
+
+impl<F: Float> Implicit<F> {
+    /// Build an implicit object from an SDF and a cheap enclosing AABB
+    /// `[min_pt, max_pt]` used for the partial-hit broad phase.
+    pub fn new(
+        name: String,
+        sdf: Box<dyn SignedDistance<F>>,
+        min_pt: Vector3D<F>,
+        max_pt: Vector3D<F>,
+        material: Box<dyn Material<F>>,
+    ) -> Self {
+        Self {
+            name,
+            sdf,
+            min_pt,
+            max_pt,
+            material,
+        }
+    }
+
+    fn epsilon() -> F {
+        F::from(1e-4f32).unwrap()
+    }
+
+    fn max_iterations() -> usize {
+        256
+    }
+
+    /// Intersect `ray` with the enclosing AABB, returning the entry/exit
+    /// distances `(t_enter, t_exit)` of the slab overlap, or `None` when the
+    /// ray misses the box. Shared by the broad phase and the sphere-tracing
+    /// narrow phase so the two never disagree about where the surface can be.
+    fn slab(&self, ray: &Ray<F>) -> Option<(F, F)> {
+        let origin = ray.origin();
+        let w_i = ray.direction();
+        let inv_dir = Vector3D::new(
+            F::one() / w_i.x,
+            F::one() / w_i.y,
+            F::one() / w_i.z,
+        );
+
+        let (tx_min, tx_max) = if w_i.x >= F::zero() {
+            (
+                (self.min_pt.x - origin.x) * inv_dir.x,
+                (self.max_pt.x - origin.x) * inv_dir.x,
+            )
+        } else {
+            (
+                (self.max_pt.x - origin.x) * inv_dir.x,
+                (self.min_pt.x - origin.x) * inv_dir.x,
+            )
+        };
+        let (ty_min, ty_max) = if w_i.y >= F::zero() {
+            (
+                (self.min_pt.y - origin.y) * inv_dir.y,
+                (self.max_pt.y - origin.y) * inv_dir.y,
+            )
+        } else {
+            (
+                (self.max_pt.y - origin.y) * inv_dir.y,
+                (self.min_pt.y - origin.y) * inv_dir.y,
+            )
+        };
+        let (tz_min, tz_max) = if w_i.z >= F::zero() {
+            (
+                (self.min_pt.z - origin.z) * inv_dir.z,
+                (self.max_pt.z - origin.z) * inv_dir.z,
+            )
+        } else {
+            (
+                (self.max_pt.z - origin.z) * inv_dir.z,
+                (self.min_pt.z - origin.z) * inv_dir.z,
+            )
+        };
+
+        let t_enter = tx_min.max(ty_min.max(tz_min));
+        let t_exit = tx_max.min(ty_max.min(tz_max));
+
+        let epsilon = F::from(1e-4f32).unwrap();
+        if t_enter < t_exit + epsilon && t_exit > F::zero() {
+            Some((t_enter, t_exit))
+        } else {
+            None
+        }
+    }
+
+    /// Central-difference gradient of the SDF, normalized to a unit normal.
+    fn normal_at(&self, p: Vector3D<F>) -> Vector3D<F> {
+        let h = Self::epsilon();
+        let ex = Vector3D::new(h, F::zero(), F::zero());
+        let ey = Vector3D::new(F::zero(), h, F::zero());
+        let ez = Vector3D::new(F::zero(), F::zero(), h);
+
+        let n = Vector3D::new(
+            self.sdf.distance(p + ex) - self.sdf.distance(p - ex),
+            self.sdf.distance(p + ey) - self.sdf.distance(p - ey),
+            self.sdf.distance(p + ez) - self.sdf.distance(p - ez),
+        );
+
+        let len = n.dot(n).sqrt();
+        n * (F::one() / len)
+    }
+}
+
+impl<F: Float> Bounded<F> for Implicit<F> {
+    fn hit(&self, ray: &Ray<F>) -> Option<Incident<F>> {
+        let origin = ray.origin();
+        let dir = ray.direction();
+
+        let epsilon = Self::epsilon();
+
+        // Confine the march to the enclosing AABB: enter at the box boundary
+        // and miss as soon as we leave it, so rays that never reach the box
+        // don't burn the full iteration cap.
+        let (t_enter, t_max) = self.slab(ray)?;
+
+        let mut t = t_enter.max(F::zero());
+        for _ in 0..Self::max_iterations() {
+            let p = origin + dir * t;
+            let d = self.sdf.distance(p);
+
+            if d.abs() < epsilon {
+                let inside = d < F::zero();
+                let normal = self.normal_at(p);
+                return Some(Incident::new(p, normal, dir, t, inside));
+            }
+
+            t = t + d;
+            if t > t_max {
+                break;
+            }
+        }
+
+        None
+    }
+}
+
+impl<F: Float> PartialBounded<F> for Implicit<F> {
+    fn partial_hit(&self, ray: &Ray<F>) -> bool {
+        self.slab(ray).is_some()
+    }
+}
+
+impl<F: Float> LightInteractable<F> for Implicit<F> {
+    fn interact(
+        &self,
+        incident: Incident<F>,
+        seed: F,
+    ) -> ProcessedIncident<F> {
+        self.material.interact(incident, seed)
+    }
+
+    fn interact_predetermined(
+        &self,
+        incident: Incident<F>,
+        w_r: Vector3D<F>,
+        pdf: F,
+        seed: F) -> ProcessedIncident<F> {
+        self.material.interact_predetermined(
+            incident,
+            w_r,
+            pdf,
+            seed,
+        )
+    }
+}
+
+impl<F: Float> RayTraceable<F> for Implicit<F> {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn area(&self) -> F {
+        F::zero()
+    }
+    fn emit(&self) -> Option<Vector3D<F>> {
+        None
+    }
+
+    fn focus(&self) -> bool {
+        self.material.focus()
+    }
+
+    fn sample_position(&self) -> (Vector3D<F>, Vector3D<F>, F) {
+        let zero = Vector3D::new(F::zero(), F::zero(), F::zero());
+        (zero, zero, F::zero())
+    }
+
+    fn sample_direction(&self, _coords: Vector3D<F>, _normal: Vector3D<F>) -> (Vector3D<F>, F) {
+        let zero = Vector3D::new(F::zero(), F::zero(), F::zero());
+        (zero, F::zero())
+    }
+
+    fn sample_light(&self) -> LightSample<F> {
+        let zero = Vector3D::new(F::zero(), F::zero(), F::zero());
+        LightSample {
+            ray: Ray::new(zero, zero),
+            normal: zero,
+            position_pdf: F::zero(),
+            direction_pdf: F::zero(),
+        }
+    }
+}
+
+/// Sphere of radius `r` centred at the origin: `len(p) - r`.
+pub struct SdfSphere<F: Float> {
+    radius: F,
+}
+
+impl<F: Float> SdfSphere<F> {
+    pub fn new(radius: F) -> Self {
+        Self { radius }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfSphere<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        p.dot(p).sqrt() - self.radius
+    }
+}
+
+/// Axis-aligned box with half-extents `b`:
+/// `len(max(abs(p) - b, 0)) + min(max(q.x, max(q.y, q.z)), 0)`.
+pub struct SdfBox<F: Float> {
+    half: Vector3D<F>,
+}
+
+impl<F: Float> SdfBox<F> {
+    pub fn new(half: Vector3D<F>) -> Self {
+        Self { half }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfBox<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        let q = Vector3D::new(
+            p.x.abs() - self.half.x,
+            p.y.abs() - self.half.y,
+            p.z.abs() - self.half.z,
+        );
+        let outside = Vector3D::new(
+            q.x.max(F::zero()),
+            q.y.max(F::zero()),
+            q.z.max(F::zero()),
+        );
+        let inside = q.x.max(q.y.max(q.z)).min(F::zero());
+        outside.dot(outside).sqrt() + inside
+    }
+}
+
+/// Torus with major radius `major` and minor radius `minor` lying in the
+/// xz-plane.
+pub struct SdfTorus<F: Float> {
+    major: F,
+    minor: F,
+}
+
+impl<F: Float> SdfTorus<F> {
+    pub fn new(major: F, minor: F) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfTorus<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        let planar = (p.x * p.x + p.z * p.z).sqrt() - self.major;
+        (planar * planar + p.y * p.y).sqrt() - self.minor
+    }
+}
+
+/// Cylinder of radius `radius` capped at `|y| = height`.
+pub struct SdfCappedCylinder<F: Float> {
+    radius: F,
+    height: F,
+}
+
+impl<F: Float> SdfCappedCylinder<F> {
+    pub fn new(radius: F, height: F) -> Self {
+        Self { radius, height }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfCappedCylinder<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        let dx = (p.x * p.x + p.z * p.z).sqrt() - self.radius;
+        let dy = p.y.abs() - self.height;
+
+        let ox = dx.max(F::zero());
+        let oy = dy.max(F::zero());
+        let inside = dx.max(dy).min(F::zero());
+        (ox * ox + oy * oy).sqrt() + inside
+    }
+}
+
+/// Boolean union of two SDFs: `min(a, b)`.
+pub struct SdfUnion<F: Float> {
+    a: Box<dyn SignedDistance<F>>,
+    b: Box<dyn SignedDistance<F>>,
+}
+
+impl<F: Float> SdfUnion<F> {
+    pub fn new(a: Box<dyn SignedDistance<F>>, b: Box<dyn SignedDistance<F>>) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfUnion<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        self.a.distance(p).min(self.b.distance(p))
+    }
+}
+
+/// Subtraction of `a` from `b`: `max(-a, b)` carves `a` out of `b`.
+pub struct SdfSubtraction<F: Float> {
+    a: Box<dyn SignedDistance<F>>,
+    b: Box<dyn SignedDistance<F>>,
+}
+
+impl<F: Float> SdfSubtraction<F> {
+    pub fn new(a: Box<dyn SignedDistance<F>>, b: Box<dyn SignedDistance<F>>) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfSubtraction<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        (-self.a.distance(p)).max(self.b.distance(p))
+    }
+}
+
+/// Boolean intersection of two SDFs: `max(a, b)`.
+pub struct SdfIntersection<F: Float> {
+    a: Box<dyn SignedDistance<F>>,
+    b: Box<dyn SignedDistance<F>>,
+}
+
+impl<F: Float> SdfIntersection<F> {
+    pub fn new(a: Box<dyn SignedDistance<F>>, b: Box<dyn SignedDistance<F>>) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfIntersection<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        self.a.distance(p).max(self.b.distance(p))
+    }
+}
+
+/// Polynomial smooth union blending two SDFs over a width `k`, so merged
+/// surfaces meet with a rounded fillet rather than a hard crease.
+pub struct SdfSmoothUnion<F: Float> {
+    a: Box<dyn SignedDistance<F>>,
+    b: Box<dyn SignedDistance<F>>,
+    k: F,
+}
+
+impl<F: Float> SdfSmoothUnion<F> {
+    pub fn new(a: Box<dyn SignedDistance<F>>, b: Box<dyn SignedDistance<F>>, k: F) -> Self {
+        Self { a, b, k }
+    }
+}
+
+impl<F: Float> SignedDistance<F> for SdfSmoothUnion<F> {
+    fn distance(&self, p: Vector3D<F>) -> F {
+        let da = self.a.distance(p);
+        let db = self.b.distance(p);
+
+        let half = F::from(0.5).unwrap();
+        let h = (half + half * (db - da) / self.k)
+            .max(F::zero())
+            .min(F::one());
+        db * (F::one() - h) + da * h - self.k * h * (F::one() - h)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raytrace::materials::Refract;
+
+    #[test]
+    fn sphere_trace_reports_front_hit_distance() {
+        // A unit sphere at the origin, enclosed by a generous AABB; a ray from
+        // z = -3 along +z should hit the front of the sphere at z = -1.
+        let implicit = Implicit::new(
+            "sdf_sphere".to_string(),
+            Box::new(SdfSphere::new(1.0)),
+            Vector3D::new(-1.5, -1.5, -1.5),
+            Vector3D::new(1.5, 1.5, 1.5),
+            Box::new(Refract::new(1.5)),
+        );
+
+        let ray = Ray::new(Vector3D::new(0.0, 0.0, -3.0), Vector3D::new(0.0, 0.0, 1.0));
+        let incident = implicit.hit(&ray).expect("ray should hit the sphere");
+
+        assert!((incident.distance() - 2.0).abs() < 1e-3);
+        assert!(!incident.inside());
+    }
+
+    #[test]
+    fn sphere_trace_misses_outside_the_box() {
+        // A ray that never enters the enclosing AABB must miss cheaply.
+        let implicit = Implicit::new(
+            "sdf_sphere".to_string(),
+            Box::new(SdfSphere::new(1.0)),
+            Vector3D::new(-1.5, -1.5, -1.5),
+            Vector3D::new(1.5, 1.5, 1.5),
+            Box::new(Refract::new(1.5)),
+        );
+
+        let ray = Ray::new(Vector3D::new(5.0, 0.0, -3.0), Vector3D::new(0.0, 0.0, 1.0));
+        assert!(implicit.hit(&ray).is_none());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::raytrace::{BVH, Incident, ProcessedIncident, Ray};
 use crate::raytrace::bvh::GenericBound;
 use crate::raytrace::materials::Material;
+use crate::raytrace::mis::{area_to_solid_angle, Emitter};
 use crate::raytrace::objects::{Bounded, LightInteractable, LightSample, PartialBounded, RayTraceable};
 
 use crate::types::Float;
@@ -8,6 +9,35 @@ use crate::vector::Vector3D;
 
 use super::base;
 
+/// Linear motion of a mesh over a single frame's shutter interval.
+///
+/// The geometry at shutter time `t` is the rest geometry translated by
+/// `velocity * (t - t0)`, so a ray sampled at `ray.time()` sees the mesh at
+/// its own instant without rebuilding any acceleration structure.
+#[derive(Debug, Clone, Copy)]
+pub struct Motion<F: Float> {
+    velocity: Vector3D<F>,
+    t0: F,
+    t1: F,
+}
+
+impl<F: Float> Motion<F> {
+    pub fn new(velocity: Vector3D<F>, t0: F, t1: F) -> Self {
+        Self { velocity, t0, t1 }
+    }
+
+    /// Shift applied to the geometry for a ray sampled at `time`.
+    fn shift(&self, time: F) -> Vector3D<F> {
+        self.velocity * (time - self.t0)
+    }
+
+    /// Per-component extent of the translation swept across the interval,
+    /// used to grow the static bounds so no triangle leaves its box.
+    fn extent(&self) -> Vector3D<F> {
+        self.velocity * (self.t1 - self.t0)
+    }
+}
+
 pub struct Mesh<F: Float> {
     name: String,
 
@@ -19,12 +49,32 @@ pub struct Mesh<F: Float> {
 
 impl<F: Float> Mesh<F> {
     pub fn new(source: String, material: Box<dyn Material<F>>) -> Self {
+        Self::new_impl(source, material, None)
+    }
+
+    /// Construct a mesh that translates linearly across the shutter interval
+    /// `[t0, t1]` for motion blur.
+    pub fn new_moving(
+        source: String,
+        material: Box<dyn Material<F>>,
+        velocity: Vector3D<F>,
+        t0: F,
+        t1: F,
+    ) -> Self {
+        Self::new_impl(source, material, Some(Motion::new(velocity, t0, t1)))
+    }
+
+    fn new_impl(
+        source: String,
+        material: Box<dyn Material<F>>,
+        motion: Option<Motion<F>>,
+    ) -> Self {
         let name = source.clone();
 
         let inner = base::Mesh::new(source);
 
-        let partial_bound = PartialBoundImpl::new(&inner);
-        let bound = BoundImpl::new(inner);
+        let partial_bound = PartialBoundImpl::new(&inner, motion);
+        let bound = BoundImpl::new(inner, motion);
 
         Self {
             name,
@@ -41,11 +91,28 @@ impl<F: Float> Mesh<F> {
 struct BoundImpl<F: Float> {
     inner: base::Mesh<F>,
 
+    motion: Option<Motion<F>>,
+
     bvh: BVH<usize, F>,
 }
 
 impl<F: Float> BoundImpl<F> {
-    pub fn new(inner: base::Mesh<F>) -> Self {
+    pub fn new(inner: base::Mesh<F>, motion: Option<Motion<F>>) -> Self {
+        // Extent the translation sweeps across the shutter interval; the box of
+        // each triangle is grown by this so the BVH, built once, still contains
+        // the geometry at every sampled instant.
+        let extent = motion.map_or(Vector3D::new(F::zero(), F::zero(), F::zero()), |m| m.extent());
+        let lo = Vector3D::new(
+            extent.x.min(F::zero()),
+            extent.y.min(F::zero()),
+            extent.z.min(F::zero()),
+        );
+        let hi = Vector3D::new(
+            extent.x.max(F::zero()),
+            extent.y.max(F::zero()),
+            extent.z.max(F::zero()),
+        );
+
         let mut bound_vec = Vec::new();
         for i in 0..inner.triangles().len() {
             let (v0, v1, v2) = inner.triangles()[i].vertices();
@@ -55,12 +122,12 @@ impl<F: Float> BoundImpl<F> {
                 v0.x.min(v1.x.min(v2.x)),
                 v0.y.min(v1.y.min(v2.y)),
                 v0.z.min(v1.z.min(v2.z)),
-            ) - epsilon;
+            ) - epsilon + lo;
             let max_pt = Vector3D::new(
                 v0.x.max(v1.x.max(v2.x)),
                 v0.y.max(v1.y.max(v2.y)),
                 v0.z.max(v1.z.max(v2.z)),
-            ) + epsilon;
+            ) + epsilon + hi;
 
             let bound = GenericBound::new(
                 i,
@@ -74,6 +141,7 @@ impl<F: Float> BoundImpl<F> {
 
         Self {
             inner,
+            motion,
             bvh,
         }
     }
@@ -85,6 +153,18 @@ impl<F: Float> BoundImpl<F> {
 
 impl<F: Float> BoundImpl<F> {
     pub fn hit(&self, ray: &Ray<F>) -> Option<Incident<F>> {
+        // Move the ray into the mesh's rest frame for its sampled instant:
+        // offsetting the origin by `-velocity * frac` is equivalent to shifting
+        // the geometry forward by `velocity * frac`.
+        let moved;
+        let ray = match &self.motion {
+            Some(m) => {
+                moved = ray.offset_origin(-m.shift(ray.time()));
+                &moved
+            }
+            None => ray,
+        };
+
         let hit_bound_vec = self.bvh.hit(ray);
         if hit_bound_vec.is_empty() {
             return None;
@@ -126,21 +206,50 @@ impl<F: Float> BoundImpl<F> {
 struct PartialBoundImpl<F: Float> {
     min_pt: Vector3D<F>,
     max_pt: Vector3D<F>,
+
+    motion: Option<Motion<F>>,
 }
 
 impl<F: Float> PartialBoundImpl<F> {
-    pub fn new(inner: &base::Mesh<F>) -> Self {
-        let (min_pt, max_pt) = inner.extreme_pts();
+    pub fn new(inner: &base::Mesh<F>, motion: Option<Motion<F>>) -> Self {
+        let (mut min_pt, mut max_pt) = inner.extreme_pts();
+
+        // Grow the enclosing AABB by the swept translation so it covers every
+        // instant of the shutter interval.
+        if let Some(m) = motion {
+            let extent = m.extent();
+            min_pt = min_pt + Vector3D::new(
+                extent.x.min(F::zero()),
+                extent.y.min(F::zero()),
+                extent.z.min(F::zero()),
+            );
+            max_pt = max_pt + Vector3D::new(
+                extent.x.max(F::zero()),
+                extent.y.max(F::zero()),
+                extent.z.max(F::zero()),
+            );
+        }
 
         Self {
             min_pt,
             max_pt,
+
+            motion,
         }
     }
 }
 
 impl<F: Float> PartialBoundImpl<F> {
     pub fn partial_hit(&self, ray: &Ray<F>) -> bool {
+        let moved;
+        let ray = match &self.motion {
+            Some(m) => {
+                moved = ray.offset_origin(-m.shift(ray.time()));
+                &moved
+            }
+            None => ray,
+        };
+
         let origin = ray.origin();
         let w_i = ray.direction();
         let inv_dir = Vector3D::new(
@@ -227,6 +336,19 @@ impl<F: Float> LightInteractable<F> for Mesh<F> {
     }
 }
 
+impl<F: Float> Emitter<F> for Mesh<F> {
+    fn directional_pdf(
+        &self,
+        _coords: Vector3D<F>,
+        w_r: Vector3D<F>,
+        distance: F,
+        light_normal: Vector3D<F>,
+    ) -> F {
+        let area_pdf = F::one() / self.area();
+        area_to_solid_angle(area_pdf, w_r, distance, light_normal)
+    }
+}
+
 impl<F: Float> RayTraceable<F> for Mesh<F> {
     fn name(&self) -> String {
         self.name.clone()
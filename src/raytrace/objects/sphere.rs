@@ -0,0 +1,182 @@
+use crate::raytrace::{Incident, ProcessedIncident, Ray};
+use crate::raytrace::objects::{Bounded, LightInteractable, LightSample, PartialBounded, RayTraceable};
+use crate::raytrace::materials::Material;
+use crate::raytrace::mis::{area_to_solid_angle, Emitter};
+
+use crate::types::Float;
+use crate::vector::Vector3D;
+
+/// An analytic sphere. Point-like emitters and perfectly round surfaces use
+/// this instead of a dense triangle mesh; it exposes the same interface as
+/// `Mesh` so it drops straight into scenes and the light-sampling path.
+pub struct Sphere<F: Float> {
+    name: String,
+
+    center: Vector3D<F>,
+    radius: F,
+
+    material: Box<dyn Material<F>>,
+}
+
+impl<F: Float> Sphere<F> {
+    pub fn new(name: String, center: Vector3D<F>, radius: F, material: Box<dyn Material<F>>) -> Self {
+        Self {
+            name,
+            center,
+            radius,
+            material,
+        }
+    }
+}
+
+impl<F: Float> Bounded<F> for Sphere<F> {
+    fn hit(&self, ray: &Ray<F>) -> Option<Incident<F>> {
+        let o = ray.origin();
+        let d = ray.direction();
+        let oc = o - self.center;
+
+        let a = d.dot(d);
+        let b = oc.dot(d);
+        let c = oc.dot(oc) - self.radius * self.radius;
+
+        let discriminant = b * b - a * c;
+        if discriminant < F::zero() {
+            return None;
+        }
+
+        let sqrt_d = discriminant.sqrt();
+        let epsilon = F::from(1e-4f32).unwrap();
+
+        // Nearest positive root of (-b ± √(b²-ac))/a.
+        let mut t = (-b - sqrt_d) / a;
+        if t < epsilon {
+            t = (-b + sqrt_d) / a;
+        }
+        if t < epsilon {
+            return None;
+        }
+
+        let p = o + d * t;
+        let inside = oc.dot(oc) < self.radius * self.radius;
+
+        // Geometric outward normal `(p - center)/r`; the `inside` flag records
+        // which side the ray came from so the material can orient itself.
+        let normal = (p - self.center) * (F::one() / self.radius);
+
+        Some(Incident::new(p, normal, d, t, inside))
+    }
+}
+
+impl<F: Float> PartialBounded<F> for Sphere<F> {
+    fn partial_hit(&self, ray: &Ray<F>) -> bool {
+        let oc = ray.origin() - self.center;
+        let d = ray.direction();
+
+        let a = d.dot(d);
+        let b = oc.dot(d);
+        let c = oc.dot(oc) - self.radius * self.radius;
+
+        let discriminant = b * b - a * c;
+        if discriminant < F::zero() {
+            return false;
+        }
+
+        // Reject spheres entirely behind the origin: the far root `t_exit` must
+        // lie in front of the ray, matching the broad-phase idiom in `bvh.rs`.
+        let t_exit = (-b + discriminant.sqrt()) / a;
+        t_exit > F::zero()
+    }
+}
+
+impl<F: Float> LightInteractable<F> for Sphere<F> {
+    fn interact(
+        &self,
+        incident: Incident<F>,
+        seed: F,
+    ) -> ProcessedIncident<F> {
+        self.material.interact(incident, seed)
+    }
+
+    fn interact_predetermined(
+        &self,
+        incident: Incident<F>,
+        w_r: Vector3D<F>,
+        pdf: F,
+        seed: F) -> ProcessedIncident<F> {
+        self.material.interact_predetermined(
+            incident,
+            w_r,
+            pdf,
+            seed,
+        )
+    }
+}
+
+impl<F: Float> Emitter<F> for Sphere<F> {
+    fn directional_pdf(
+        &self,
+        _coords: Vector3D<F>,
+        w_r: Vector3D<F>,
+        distance: F,
+        light_normal: Vector3D<F>,
+    ) -> F {
+        let area_pdf = F::one() / self.area();
+        area_to_solid_angle(area_pdf, w_r, distance, light_normal)
+    }
+}
+
+impl<F: Float> RayTraceable<F> for Sphere<F> {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn area(&self) -> F {
+        let four = F::from(4.0).unwrap();
+        let pi = F::from(std::f64::consts::PI).unwrap();
+        four * pi * self.radius * self.radius
+    }
+    fn emit(&self) -> Option<Vector3D<F>> {
+        None
+    }
+
+    fn focus(&self) -> bool {
+        self.material.focus()
+    }
+
+    fn sample_position(&self) -> (Vector3D<F>, Vector3D<F>, F) {
+        // Archimedes' method: a uniform height `z` plus a uniform azimuth gives
+        // a uniform point on the unit sphere.
+        let one = F::one();
+        let two = F::from(2.0).unwrap();
+        let pi = F::from(std::f64::consts::PI).unwrap();
+
+        let z = one - two * F::sample_rand();
+        let r = (one - z * z).max(F::zero()).sqrt();
+        let phi = two * pi * F::sample_rand();
+
+        let dir = Vector3D::new(r * phi.cos(), r * phi.sin(), z);
+        let coords = self.center + dir * self.radius;
+
+        let position_pdf = one / self.area();
+
+        (coords, dir, position_pdf)
+    }
+
+    fn sample_direction(&self, _coords: Vector3D<F>, normal: Vector3D<F>) -> (Vector3D<F>, F) {
+        (normal, F::one())
+    }
+
+    fn sample_light(&self) -> LightSample<F> {
+        let (coords, normal, position_pdf) = self.sample_position();
+        let (direction, direction_pdf) = self.sample_direction(coords, normal);
+
+        let ray = Ray::new(coords, direction);
+
+        LightSample {
+            ray,
+            normal,
+            position_pdf,
+            direction_pdf,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::types::Float;
+use crate::vector::Vector3D;
+
+/// A ray carrying the shutter time at which it was sampled. Averaging many
+/// rays with jittered `time` over a frame's shutter interval `[t0, t1]` gives
+/// motion blur, since each ray sees moving geometry at its own instant.
+#[derive(Debug, Clone, Copy)]
+pub struct Ray<F: Float> {
+    origin: Vector3D<F>,
+    direction: Vector3D<F>,
+
+    time: F,
+}
+
+impl<F: Float> Ray<F> {
+    /// A ray at the start of the shutter (`time = 0`), for static geometry.
+    pub fn new(origin: Vector3D<F>, direction: Vector3D<F>) -> Self {
+        Self {
+            origin,
+            direction,
+            time: F::zero(),
+        }
+    }
+
+    /// A ray sampled at an explicit shutter `time`.
+    pub fn new_at_time(origin: Vector3D<F>, direction: Vector3D<F>, time: F) -> Self {
+        Self {
+            origin,
+            direction,
+            time,
+        }
+    }
+
+    /// A ray whose `time` is drawn uniformly over the shutter interval
+    /// `[t0, t1]`, so repeated samples converge to the motion-blurred image.
+    pub fn sample_shutter(origin: Vector3D<F>, direction: Vector3D<F>, t0: F, t1: F) -> Self {
+        let time = t0 + (t1 - t0) * F::sample_rand();
+        Self {
+            origin,
+            direction,
+            time,
+        }
+    }
+
+    pub fn origin(&self) -> Vector3D<F> {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Vector3D<F> {
+        self.direction
+    }
+
+    pub fn time(&self) -> F {
+        self.time
+    }
+
+    /// A copy of this ray with its origin translated by `offset`, preserving
+    /// the direction and sampled time. Used to trace against moving geometry
+    /// in its rest frame.
+    pub fn offset_origin(&self, offset: Vector3D<F>) -> Self {
+        Self {
+            origin: self.origin + offset,
+            direction: self.direction,
+            time: self.time,
+        }
+    }
+}